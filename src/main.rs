@@ -79,6 +79,9 @@ fn method_syntax() {
     //  they're defined in the context of a struct, enum or trait object
     //  they're first parameter is always self which represents the instance of the struct the method is being called on
 
+    // #[derive(Debug)] opts the struct into the Debug trait so the whole
+    // instance can be printed at once with {:?} or {:#?} instead of field by field
+    #[derive(Debug)]
     struct Rectangle {
         width: u32,
         height: u32,
@@ -96,11 +99,21 @@ fn method_syntax() {
         }
     }
 
+    let scale = 2;
     let rect1 = Rectangle {
-        width: 30,
+        // dbg! prints to stderr (unlike println!'s stdout), and includes the
+        // file, line number and the expression text along with its value
+        // it also takes ownership of the value and hands it right back,
+        // so it can sit inline inside an expression like this
+        width: dbg!(30 * scale),
         height: 50,
     };
 
+    // {:?} prints the whole struct on one line
+    println!("rect1 is {:?}", rect1);
+    // {:#?} pretty-prints it across multiple lines, one field per line
+    println!("rect1 is {:#?}", rect1);
+
     let rect2 = Rectangle {
         width: 50,
         height: 100,
@@ -145,7 +158,155 @@ fn method_syntax() {
     // same thing because of the ar&d rust has
 }
 
+// Walks through the same area calculation three times, each version
+// refactoring the last, to show why structs are an improvement over
+// loose parameters or tuples
+fn area_example() {
+    // version 1: two loose u32 parameters
+    // works, but nothing ties width and height together as "one rectangle"
+    // and it's easy to accidentally swap the arguments when calling it
+    fn area(width: u32, height: u32) -> u32 {
+        width * height
+    }
+
+    let width1 = 30;
+    let height1 = 50;
+    println!(
+        "The area of the rectangle is {} square pixels.",
+        area(width1, height1)
+    );
+
+    // version 2: a single tuple parameter
+    // groups the two values together, which is a bit better, but .0 and .1
+    // don't say which is width and which is height, so it's easy to
+    // misuse the tuple once there's more than one value in it
+    fn area_tuple(dimensions: (u32, u32)) -> u32 {
+        dimensions.0 * dimensions.1
+    }
+
+    let rect = (30, 50);
+    println!(
+        "The area of the rectangle is {} square pixels.",
+        area_tuple(rect)
+    );
+
+    // version 3: a borrowed Rectangle struct
+    // the fields are named, so width and height can't be mixed up, and
+    // borrowing with &Rectangle means area_struct() doesn't take ownership of
+    // the struct, so the caller can keep using it afterwards
+    struct Rectangle {
+        width: u32,
+        height: u32,
+    }
+
+    fn area_struct(rectangle: &Rectangle) -> u32 {
+        rectangle.width * rectangle.height
+    }
+
+    let rect1 = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    println!(
+        "The area of the rectangle is {} square pixels.",
+        area_struct(&rect1)
+    );
+}
+
+// dai_structs() uses owned Strings because a struct can't normally hold a
+// reference without telling the compiler how long that reference is good
+// for. Adding an explicit lifetime parameter lets a struct store &str
+// slices instead, as long as the compiler can verify the data being
+// referenced outlives the struct that refers to it
+fn struct_lifetimes() {
+    // 'a says: every reference stored in a UserRef can't outlive the data
+    // it's borrowed from
+    struct UserRef<'a> {
+        username: &'a str,
+        email: &'a str,
+    }
+
+    let username = String::from("random");
+    let email = String::from("random@email.com");
+
+    // user_ref borrows from username and email, so the compiler requires
+    // that username and email are still alive for as long as user_ref is
+    let user_ref = UserRef {
+        username: &username,
+        email: &email,
+    };
+
+    println!(
+        "UserRef {{ username: {}, email: {} }}",
+        user_ref.username, user_ref.email
+    );
+    // if username or email were dropped while user_ref was still around,
+    // this wouldn't compile - that's the lifetime annotation doing its job
+}
+
+// method_syntax() notes that multiple impl blocks are useful for
+// separating generic types and traits, but only ever shows Rectangle,
+// which has no generic parameter to separate anything from
+fn generic_point() {
+    // Point<T> can hold any single type T for both x and y
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    // impl<T> applies to every Point<T>, no matter what T is
+    impl<T> Point<T> {
+        fn x(&self) -> &T {
+            &self.x
+        }
+    }
+
+    // this impl block only applies when T is f32, so distance_from_origin
+    // is only available on a Point<f32>, not on a Point<i32> or Point<String>
+    impl Point<f32> {
+        fn distance_from_origin(&self) -> f32 {
+            (self.x.powi(2) + self.y.powi(2)).sqrt()
+        }
+    }
+
+    let integer_point = Point { x: 5, y: 10 };
+    let float_point = Point { x: 3.0, y: 4.0 };
+
+    println!("integer_point.x = {}", integer_point.x());
+    println!("float_point.x = {}", float_point.x());
+    println!(
+        "float_point is {} units from the origin",
+        float_point.distance_from_origin()
+    );
+
+    // a struct can also take more than one generic type, so x and y
+    // don't have to be the same type as each other
+    struct PointXY<T, U> {
+        x: T,
+        y: U,
+    }
+
+    impl<T, U> PointXY<T, U> {
+        // mixup consumes self and other and produces a new PointXY made
+        // from self's x and other's y, which may be different types
+        fn mixup<V, W>(self, other: PointXY<V, W>) -> PointXY<T, W> {
+            PointXY {
+                x: self.x,
+                y: other.y,
+            }
+        }
+    }
+
+    let p1 = PointXY { x: 5, y: 10.4 };
+    let p2 = PointXY { x: "Hello", y: 'c' };
+    let p3 = p1.mixup(p2);
+    println!("p3.x = {}, p3.y = {}", p3.x, p3.y);
+}
+
 fn main() {
     dai_structs();
     method_syntax();
+    area_example();
+    struct_lifetimes();
+    generic_point();
 }